@@ -1,6 +1,7 @@
 use std::env::VarError;
 use std::io::Error as IoError;
 
+use error_chain::error_chain;
 use mercury::Error as MercuryError;
 
 error_chain!{
@@ -1,12 +1,3 @@
-extern crate clap;
-extern crate dotenv;
-#[macro_use]
-extern crate error_chain;
-extern crate futures;
-extern crate html2text;
-extern crate mercury;
-extern crate tokio_core;
-
 mod error;
 
 use std::env;
@@ -14,22 +5,23 @@ use std::io::{self, Write};
 
 use clap::{App, AppSettings};
 use dotenv::dotenv;
-use futures::Future;
 use mercury::{Article, Mercury};
-use tokio_core::reactor::Core;
 
-use error::{Error, Result};
+use error::Result;
 
-quick_main!(run);
+#[tokio::main]
+async fn main() {
+    if let Err(ref e) = run().await {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
 
-fn run() -> Result<i32> {
+async fn run() -> Result<()> {
     dotenv().ok();
 
-    let mut core = Core::new()?;
-    let handle = core.handle();
-
     let key = env::var("MERCURY_API_KEY")?;
-    let client = Mercury::new(&handle, key)?;
+    let client = Mercury::new(key);
 
     let matches = App::new("Mercury Reader")
         .version("0.1")
@@ -40,12 +32,12 @@ fn run() -> Result<i32> {
         .get_matches();
 
     let url = matches.value_of("url").unwrap_or_else(|| unreachable!());
-    let task = client.parse(url).map_err(Error::from).and_then(render);
+    let article = client.parse(url).await?;
 
-    core.run(task)
+    render(article)
 }
 
-fn render(article: Article) -> Result<i32> {
+fn render(article: Article) -> Result<()> {
     let stdout = io::stdout();
     let mut handle = stdout.lock();
 
@@ -68,5 +60,5 @@ fn render(article: Article) -> Result<i32> {
 
     handle.write(&[10])?;
 
-    Ok(0)
+    Ok(())
 }
@@ -1,9 +1,8 @@
 use std::env::VarError;
 use std::io::Error as IoError;
 
-use futures::sync::oneshot::Canceled;
+use error_chain::error_chain;
 use mercury::Error as MercuryError;
-use rocket::error::LaunchError;
 
 error_chain!{
     links {
@@ -11,9 +10,7 @@ error_chain!{
     }
 
     foreign_links {
-        Canceled(Canceled);
         Env(VarError);
         Io(IoError);
-        Launch(LaunchError);
     }
 }
@@ -1,46 +1,39 @@
-#![feature(plugin)]
-#![plugin(rocket_codegen)]
-
-extern crate dotenv;
 #[macro_use]
-extern crate error_chain;
-extern crate futures;
-extern crate mercury;
 extern crate rocket;
-extern crate rocket_contrib;
-extern crate tokio_core;
 
 mod error;
-mod reader;
 
+use std::env;
 use std::path::{Path, PathBuf};
 
-use rocket_contrib::Template;
-use rocket::response::NamedFile;
+use dotenv::dotenv;
+use mercury::Mercury;
+use rocket::fs::NamedFile;
+use rocket::response::Debug;
 use rocket::State;
+use rocket_dyn_templates::Template;
 
-use error::Result;
-use reader::Reader;
-
-quick_main!(run);
+use error::Error;
 
 #[get("/read?<url>")]
-fn read(url: &str, reader: State<Reader>) -> Result<Template> {
-    let article = reader.parse(&url[4..])?;
+async fn read(url: &str, merc: &State<Mercury>) -> Result<Template, Debug<Error>> {
+    let article = merc.parse(&url[4..]).await.map_err(Error::from)?;
     Ok(Template::render("index", &article))
 }
 
 #[get("/<file..>")]
-fn files(file: PathBuf) -> Option<NamedFile> {
-    NamedFile::open(Path::new("static/").join(file)).ok()
+async fn files(file: PathBuf) -> Option<NamedFile> {
+    NamedFile::open(Path::new("static/").join(file)).await.ok()
 }
 
-fn run() -> Result<()> {
-    let e = rocket::ignite()
+#[launch]
+fn rocket() -> _ {
+    dotenv().ok();
+
+    let key = env::var("MERCURY_API_KEY").expect("MERCURY_API_KEY must be set");
+
+    rocket::build()
         .mount("/", routes![files, read])
         .attach(Template::fairing())
-        .manage(Reader::new()?)
-        .launch();
-
-    Err(e.into())
+        .manage(Mercury::new(key))
 }
@@ -1,17 +1,18 @@
-use hyper::error::{Error as HyperError, UriError};
+use error_chain::error_chain;
+use http::uri::InvalidUri;
+use http::Error as HttpError;
+use hyper::header::InvalidHeaderValue;
+use hyper::Error as HyperError;
 use native_tls::Error as TlsError;
 use serde_json::Error as JsonError;
 
 error_chain!{
     foreign_links {
-        Http(HyperError);
+        Header(InvalidHeaderValue);
+        Http(HttpError);
+        Hyper(HyperError);
         Json(JsonError);
         Tls(TlsError);
-    }
-}
-
-impl From<UriError> for Error {
-    fn from(e: UriError) -> Error {
-        HyperError::from(e).into()
+        Uri(InvalidUri);
     }
 }
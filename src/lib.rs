@@ -18,9 +18,8 @@
 //!
 //! ```toml
 //! [dependencies]
-//! futures = "0.1"
-//! mercury = "0.1"
-//! tokio-core = "0.1"
+//! mercury = "0.2"
+//! tokio = { version = "1", features = ["macros", "rt-multi-thread"] }
 //! ```
 //!
 //! ### Example
@@ -28,43 +27,24 @@
 //! Additional examples can be found on [GitHub][Source Code].
 //!
 //! ```
-//! # extern crate dotenv;
-//! # extern crate futures;
-//! # extern crate mercury;
-//! # extern crate tokio_core;
-//! #
 //! # use std::{env, error};
 //! #
-//! # use dotenv::dotenv;
-//! # use futures::Future;
 //! # use mercury::Mercury;
-//! # use tokio_core::reactor::Core;
-//! #
-//! # type Error = Box<error::Error>;
 //! #
-//! # fn main() {
-//! #     dotenv().ok();
-//! #     example().unwrap();
-//! # }
+//! # type Error = Box<dyn error::Error>;
 //! #
-//! # fn example() -> Result<(), Error> {
-//! // Create a new event loop with tokio.
-//! let mut core = Core::new()?;
-//! let handle = core.handle();
-//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Error> {
 //! // Load your API key from the environment.
 //! let key = env::var("MERCURY_API_KEY")?;
 //!
-//! // Pass a handle to the event loop and the API key to the Mercury constructor.
-//! let client = Mercury::new(&handle, key)?;
-//!
-//! // The parse method returns a Future that will resolve to a parsed Article.
-//! let future = client.parse("https://example.com").inspect(|article| {
-//!     println!("{:#?}", article);
-//! });
+//! // Build a new client. No event loop to set up; `Mercury` is `Send + Sync`
+//! // and can be cloned freely.
+//! let client = Mercury::new(key);
 //!
-//! // Block the current thread until the future completes.
-//! core.run(future)?;
+//! // Parse an article and await the result.
+//! let article = client.parse("https://example.com").await?;
+//! println!("{:#?}", article);
 //! #
 //! # Ok(())
 //! # }
@@ -74,46 +54,31 @@
 //! [Mercury Parser]: https://mercury.postlight.com/web-parser
 //! [Source Code]: https://github.com/postlight/mercury-rs
 
-extern crate chrono;
-#[macro_use]
-extern crate error_chain;
-extern crate futures;
-#[macro_use]
-extern crate hyper;
-extern crate hyper_tls;
-extern crate native_tls;
-extern crate num_cpus;
-extern crate serde;
-#[macro_use]
-extern crate serde_derive;
-extern crate serde_json;
-extern crate tokio_core;
-
 mod article;
 
 /// Types representing errors that can occur.
 pub mod error;
 
-use std::rc::Rc;
+use std::sync::Arc;
 
-use futures::{stream, Future, IntoFuture, Poll, Stream};
+use hyper::client::HttpConnector;
+use hyper::header::HeaderValue;
+use hyper::{Body, Client, Request, Uri};
 use hyper_tls::HttpsConnector;
-use hyper::{Get, Request, Uri};
-use hyper::client::{Client, HttpConnector};
-use tokio_core::reactor::Handle;
+use serde::Deserialize;
 
-pub use article::*;
-pub use error::Error;
+pub use crate::article::*;
+pub use crate::error::Error;
 
-const ENDPOINT: &'static str = "https://mercury.postlight.com/parser";
+const ENDPOINT: &str = "https://mercury.postlight.com/parser";
 
 type Connect = HttpsConnector<HttpConnector>;
 
 /// A client used to make requests to the [Mercury Parser].
 ///
 /// [Mercury Parser]: https://mercury.postlight.com/web-parser
-#[derive(Debug)]
-pub struct Mercury(Rc<Inner>);
+#[derive(Clone, Debug)]
+pub struct Mercury(Arc<Inner>);
 
 impl Mercury {
     /// Create a new Mercury client.
@@ -121,42 +86,17 @@ impl Mercury {
     /// # Example
     ///
     /// ```
-    /// # extern crate dotenv;
-    /// # extern crate futures;
-    /// # extern crate mercury;
-    /// # extern crate tokio_core;
+    /// # use std::env;
     /// #
-    /// # use std::{env, error};
-    /// #
-    /// # use dotenv::dotenv;
-    /// # use futures::Future;
     /// # use mercury::Mercury;
-    /// # use tokio_core::reactor::Core;
-    /// #
-    /// # type Error = Box<error::Error>;
     /// #
     /// # fn main() {
-    /// #     dotenv().ok();
-    /// #     example().unwrap();
-    /// # }
-    /// #
-    /// # fn example() -> Result<(), Error> {
-    /// let core = Core::new()?;
-    /// let handle = core.handle();
-    ///
-    /// let key = env::var("MERCURY_API_KEY")?;
-    /// let client = Mercury::new(&handle, key)?;
-    /// #
-    /// # Ok(())
+    /// let key = env::var("MERCURY_API_KEY").unwrap();
+    /// let client = Mercury::new(key);
     /// # }
     /// ```
-    pub fn new(handle: &Handle, key: String) -> Result<Mercury, Error> {
-        Inner::new(handle, key).map(Rc::new).map(Mercury)
-    }
-
-    /// Return a reference to a handle to the event loop this client is associated with.
-    pub fn handle(&self) -> &Handle {
-        self.client().handle()
+    pub fn new(key: String) -> Mercury {
+        Mercury(Arc::new(Inner::new(key)))
     }
 
     /// Returns a reference to the API key associated with this client.
@@ -169,58 +109,35 @@ impl Mercury {
     /// # Example
     ///
     /// ```
-    /// # extern crate dotenv;
-    /// # extern crate futures;
-    /// # extern crate mercury;
-    /// # extern crate tokio_core;
+    /// # use std::env;
     /// #
-    /// # use std::{env, error};
-    /// #
-    /// # use dotenv::dotenv;
-    /// # use futures::Future;
     /// # use mercury::Mercury;
-    /// # use tokio_core::reactor::Core;
-    /// #
-    /// # type Error = Box<error::Error>;
     /// #
-    /// # fn main() {
-    /// #     dotenv().ok();
-    /// #     example().unwrap();
-    /// # }
-    /// #
-    /// # fn example() -> Result<(), Error> {
-    /// # let mut core = Core::new()?;
-    /// # let handle = core.handle();
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), mercury::Error> {
+    /// # let key = env::var("MERCURY_API_KEY").unwrap();
+    /// # let client = Mercury::new(key);
     /// #
-    /// # let key = env::var("MERCURY_API_KEY")?;
-    /// # let client = Mercury::new(&handle, key)?;
+    /// let article = client.parse("https://example.com").await?;
+    /// println!("{:#?}", article);
     /// #
-    /// let future = client.parse("https://example.com").inspect(|article| {
-    ///     println!("{:#?}", article);
-    /// });
-    /// #
-    /// # core.run(future.then(|_| Ok(())))
+    /// # Ok(())
     /// # }
     /// ```
-    pub fn parse(&self, resource: &str) -> Response {
-        let merc = Mercury::clone(self);
-        let f = build_url(resource).into_future().and_then(move |url| {
-            let mut req = Request::new(Get, url);
+    pub async fn parse(&self, resource: &str) -> Result<Article, Error> {
+        let url = build_url(resource)?;
 
-            header!{ (XApiKey, "X-Api-Key") => [String] }
-            req.headers_mut().set(XApiKey(merc.key().to_owned()));
+        let mut req = Request::get(url).body(Body::empty())?;
+        req.headers_mut()
+            .insert("X-Api-Key", HeaderValue::from_str(self.key())?);
 
-            merc.client()
-                .request(req)
-                .and_then(|resp| resp.body().map(stream::iter_ok).flatten().collect())
-                .map_err(Error::from)
-                .and_then(|body| match serde_json::from_slice(&body)? {
-                    ParserResult::Ok(article) => Ok(article),
-                    ParserResult::Err { msg, msgs } => bail!(msg.unwrap_or(msgs)),
-                })
-        });
+        let resp = self.client().request(req).await?;
+        let body = hyper::body::to_bytes(resp.into_body()).await?;
 
-        Response::new(Box::new(f))
+        match serde_json::from_slice(&body)? {
+            ParserResult::Ok(article) => Ok(article),
+            ParserResult::Err { msg, msgs } => Err(Error::from(msg.unwrap_or(msgs))),
+        }
     }
 
     /// Returns a reference to the underlying hyper client.
@@ -229,37 +146,6 @@ impl Mercury {
     }
 }
 
-impl Clone for Mercury {
-    /// Increments the strong reference count of the underlying [`Rc`] pointer.
-    ///
-    /// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
-    fn clone(&self) -> Mercury {
-        Mercury(Rc::clone(&self.0))
-    }
-}
-
-/// A [`Future`] that will resolve to a parsed [`Article`].
-///
-/// [`Article`]: ./struct.Article.html
-/// [`Future`]: ../futures/future/trait.Future.html
-#[must_use = "futures do nothing unless polled"]
-pub struct Response(Box<Future<Item = Article, Error = Error>>);
-
-impl Response {
-    fn new(f: Box<Future<Item = Article, Error = Error>>) -> Response {
-        Response(f)
-    }
-}
-
-impl Future for Response {
-    type Item = Article;
-    type Error = Error;
-
-    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
-        self.0.poll()
-    }
-}
-
 #[derive(Debug)]
 struct Inner {
     client: Client<Connect>,
@@ -267,15 +153,13 @@ struct Inner {
 }
 
 impl Inner {
-    fn new(handle: &Handle, key: String) -> Result<Inner, Error> {
-        let conn = Connect::new(num_cpus::get(), handle)?;
-        let client = Client::configure().connector(conn).build(handle);
+    fn new(key: String) -> Inner {
+        let client = Client::builder().build(HttpsConnector::new());
 
-        Ok(Inner { client, key })
+        Inner { client, key }
     }
 }
 
-#[cfg_attr(feature = "cargo-clippy", allow(large_enum_variant))]
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum ParserResult {